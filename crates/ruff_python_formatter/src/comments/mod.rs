@@ -89,6 +89,7 @@
 //!
 //! It is possible to add an additional optional label to [`SourceComment`] If ever the need arises to distinguish two *dangling comments* in the formatting logic,
 
+use std::borrow::Cow;
 use std::cell::Cell;
 use std::fmt::{Debug, Formatter};
 use std::rc::Rc;
@@ -102,6 +103,108 @@ use crate::comments::map::MultiMap;
 use crate::comments::node_key::NodeRefEqualityKey;
 use ruff_formatter::{SourceCode, SourceCodeSlice};
 use ruff_python_ast::node::AnyNodeRef;
+use ruff_text_size::TextRange;
+
+/// Whether a comment appears on its own line or shares a line with preceding source code.
+///
+/// ```python
+/// a = 10
+/// # Own line comment
+/// b = 20 # End of line comment
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum CommentTextPosition {
+    /// The comment is on its own line, with only whitespace preceding it on that line.
+    OwnLine,
+
+    /// The comment follows source code on the same line.
+    EndOfLine,
+}
+
+impl CommentTextPosition {
+    /// Returns `true` if the comment is on its own line.
+    pub(crate) const fn is_own_line(self) -> bool {
+        matches!(self, CommentTextPosition::OwnLine)
+    }
+
+    /// Returns `true` if the comment shares its line with preceding source code.
+    pub(crate) const fn is_end_of_line(self) -> bool {
+        matches!(self, CommentTextPosition::EndOfLine)
+    }
+}
+
+/// The semantic meaning of a comment's text, independent of where it's attached in the tree.
+///
+/// Most comments are [`CommentKind::Regular`] and carry no special meaning to the formatter.
+/// The other variants flag comments that a tool (the formatter or the linter) gives special
+/// treatment, so that rules don't need to re-parse the comment text to find out.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub(crate) enum CommentKind {
+    /// A regular, free-form comment.
+    #[default]
+    Regular,
+
+    /// A shebang line, e.g. `#!/usr/bin/env python`, on the first line of the file.
+    Shebang,
+
+    /// A `# fmt: off`, `# fmt: on`, or `# fmt: skip` directive that suppresses formatting.
+    FormatterDirective,
+
+    /// A `# noqa` suppression comment recognized by the linter.
+    Noqa,
+
+    /// A PEP 484 style type comment, e.g. `# type: int`.
+    TypeComment,
+}
+
+impl CommentKind {
+    /// Returns `true` if this comment instructs a tool to suppress its output for the commented code,
+    /// e.g. a `# fmt: skip` or `# noqa` comment.
+    pub(crate) const fn is_suppression(self) -> bool {
+        matches!(self, CommentKind::FormatterDirective | CommentKind::Noqa)
+    }
+
+    /// Returns `true` if the comment's significant text must be preserved verbatim, rather than
+    /// going through the usual spacing normalization applied to regular comments.
+    const fn is_verbatim(self) -> bool {
+        matches!(
+            self,
+            CommentKind::Shebang
+                | CommentKind::FormatterDirective
+                | CommentKind::Noqa
+                | CommentKind::TypeComment
+        )
+    }
+
+    /// Classifies the text of a comment, independent of its position in the source.
+    ///
+    /// `is_first_line` indicates whether the comment starts on the first line of the file, which
+    /// is required for it to be recognized as a shebang.
+    fn from_comment_text(text: &str, is_first_line: bool) -> Self {
+        if is_first_line && text.starts_with("#!") {
+            return CommentKind::Shebang;
+        }
+
+        // Only the single `#` that introduces the comment is insignificant; a comment starting
+        // with `##` is a banner, not a directive, even if the rest of the text matches one.
+        let trimmed = text.strip_prefix('#').unwrap_or(text).trim();
+
+        if matches!(
+            trimmed,
+            "fmt: off" | "fmt: on" | "fmt: skip" | "fmt:off" | "fmt:on" | "fmt:skip"
+        ) {
+            CommentKind::FormatterDirective
+        } else if trimmed.eq_ignore_ascii_case("noqa")
+            || trimmed.to_ascii_lowercase().starts_with("noqa:")
+        {
+            CommentKind::Noqa
+        } else if trimmed.starts_with("type:") {
+            CommentKind::TypeComment
+        } else {
+            CommentKind::Regular
+        }
+    }
+}
 
 /// A comment in the source document.
 #[derive(Debug, Clone)]
@@ -109,18 +212,85 @@ pub(crate) struct SourceComment {
     /// The location of the comment in the source document.
     pub(super) slice: SourceCodeSlice,
 
+    /// Whether the comment is on its own line or trails the preceding source code.
+    pub(super) position: CommentTextPosition,
+
+    /// The semantic kind of the comment, e.g. whether it's a suppression directive.
+    pub(super) kind: CommentKind,
+
     /// Whether the comment has been formatted or not.
     #[cfg(debug_assertions)]
     pub(super) formatted: Cell<bool>,
 }
 
 impl SourceComment {
+    /// Creates a new comment for the given `slice`, classifying its [`position`](SourceComment::position)
+    /// and [`kind`](SourceComment::kind) by looking at the surrounding source text.
+    pub(super) fn new(slice: SourceCodeSlice, source_code: SourceCode) -> Self {
+        let position = Self::text_position(&slice, source_code);
+        let text = slice.text(source_code);
+        let is_first_line = source_code
+            .slice(TextRange::up_to(slice.start()))
+            .is_empty();
+        let kind = CommentKind::from_comment_text(text, is_first_line);
+
+        Self {
+            slice,
+            position,
+            kind,
+            #[cfg(debug_assertions)]
+            formatted: Cell::new(false),
+        }
+    }
+
+    /// Determines the [`CommentTextPosition`] of a comment by scanning backwards from its start
+    /// to see whether only whitespace precedes the `#` on that physical line.
+    fn text_position(slice: &SourceCodeSlice, source_code: SourceCode) -> CommentTextPosition {
+        let preceding = source_code.slice(TextRange::up_to(slice.start()));
+
+        let line_start = preceding.rfind(['\n', '\r']).map_or(0, |index| index + 1);
+
+        if preceding[line_start..].trim().is_empty() {
+            CommentTextPosition::OwnLine
+        } else {
+            CommentTextPosition::EndOfLine
+        }
+    }
+
     /// Returns the location of the comment in the original source code.
     /// Allows retrieving the text of the comment.
     pub(crate) fn slice(&self) -> &SourceCodeSlice {
         &self.slice
     }
 
+    /// Returns whether the comment is on its own line or trails the preceding source code.
+    pub(crate) const fn position(&self) -> CommentTextPosition {
+        self.position
+    }
+
+    /// Returns the semantic [`CommentKind`] of the comment.
+    pub(crate) const fn kind(&self) -> CommentKind {
+        self.kind
+    }
+
+    /// Returns `true` if the comment is a suppression directive understood by the formatter or
+    /// linter, e.g. `# fmt: skip` or `# noqa`.
+    pub(crate) const fn is_suppression(&self) -> bool {
+        self.kind.is_suppression()
+    }
+
+    /// Returns the comment's text with its spacing normalized: exactly one space between the
+    /// leading `#` run (kept as-is, so `## Heading` stays a banner) and the text, and trailing
+    /// whitespace trimmed.
+    ///
+    /// Shebangs, cell markers (`#%%`), type comments, formatter/linter directives, and a bare `#`
+    /// (or a run of `#`s with no text following) only have their trailing whitespace trimmed;
+    /// the rest of their text is returned unchanged because their significant text (or absence
+    /// of text) must be preserved exactly.
+    pub(crate) fn normalized_text<'a>(&self, source_code: SourceCode<'a>) -> Cow<'a, str> {
+        normalize_comment_text(self.slice.text(source_code), self.kind.is_verbatim())
+    }
+
     #[cfg(not(debug_assertions))]
     #[inline(always)]
     pub fn mark_formatted(&self) {}
@@ -138,6 +308,28 @@ impl SourceComment {
         DebugComment::new(self, source_code)
     }
 }
+
+/// Normalizes the spacing of a comment's `text`, unless `is_verbatim` is set, in which case the
+/// text is returned with only its trailing whitespace trimmed. Pulled out of [`SourceComment::normalized_text`] as a pure function
+/// of its text so the spacing rules can be unit tested without constructing a [`SourceCode`].
+fn normalize_comment_text(text: &str, is_verbatim: bool) -> Cow<'_, str> {
+    let text = text.trim_end();
+
+    if is_verbatim || text.starts_with("#%%") {
+        return Cow::Borrowed(text);
+    }
+
+    let hash_len = text.bytes().take_while(|&byte| byte == b'#').count();
+    let (hashes, content) = text.split_at(hash_len);
+    let content = content.trim_start();
+
+    // A bare `#`, or a run of `#`s with no text following, is left untouched.
+    if content.is_empty() {
+        return Cow::Borrowed(text);
+    }
+
+    Cow::Owned(format!("{hashes} {content}"))
+}
 type CommentsMap<'a> = MultiMap<NodeRefEqualityKey<'a>, SourceComment>;
 
 /// The comments of a syntax tree stored by node.
@@ -216,6 +408,28 @@ impl<'a> Comments<'a> {
         !self.trailing_comments(node).is_empty()
     }
 
+    /// Returns a consuming cursor over the `node`'s [leading comments](self#leading-comments).
+    ///
+    /// Unlike [`leading_comments`](Comments::leading_comments), advancing the cursor with
+    /// [`CommentCursor::next`] marks the returned comment as formatted, so rules that use the
+    /// cursor can't forget to call `mark_formatted` themselves.
+    #[inline]
+    pub(crate) fn leading_cursor(&self, node: AnyNodeRef<'a>) -> CommentCursor<'_> {
+        CommentCursor::new(self.leading_comments(node))
+    }
+
+    /// Returns a consuming cursor over the `node`'s [dangling comments](self#dangling-comments).
+    #[inline]
+    pub(crate) fn dangling_cursor(&self, node: AnyNodeRef<'a>) -> CommentCursor<'_> {
+        CommentCursor::new(self.dangling_comments(node))
+    }
+
+    /// Returns a consuming cursor over the `node`'s [trailing comments](self#trailing-comments).
+    #[inline]
+    pub(crate) fn trailing_cursor(&self, node: AnyNodeRef<'a>) -> CommentCursor<'_> {
+        CommentCursor::new(self.trailing_comments(node))
+    }
+
     /// Returns an iterator over the [leading](self#leading-comments) and [trailing comments](self#trailing-comments) of `node`.
     pub(crate) fn leading_trailing_comments(
         &self,
@@ -266,9 +480,436 @@ impl<'a> Comments<'a> {
     pub(crate) fn debug(&'a self, source_code: SourceCode<'a>) -> DebugComments<'a> {
         DebugComments::new(&self.data.comments, source_code)
     }
+
+    /// Returns the contiguous [`CommentBlock`] that `comment` belongs to among `node`'s
+    /// [leading](self#leading-comments), [dangling](self#dangling-comments), and
+    /// [trailing](self#trailing-comments) comments.
+    ///
+    /// A block is a run of consecutive own-line comments of the same [`CommentKind`] with no
+    /// blank source line between them; it breaks as soon as the kind changes or a blank line or a
+    /// line of code intervenes. Returns `None` if `comment` isn't one of `node`'s own-line comments.
+    pub(crate) fn comment_block_containing(
+        &self,
+        node: AnyNodeRef<'a>,
+        comment: &SourceComment,
+        source_code: SourceCode,
+    ) -> Option<CommentBlock<'_>> {
+        let comments: Vec<&SourceComment> = self.leading_dangling_trailing_comments(node).collect();
+
+        let index = comments
+            .iter()
+            .position(|candidate| std::ptr::eq(*candidate, comment))?;
+
+        let (start, end) = comment_block_range(&comments, index, source_code)?;
+
+        Some(CommentBlock {
+            comments: comments[start..=end].to_vec(),
+        })
+    }
+}
+
+/// Computes the inclusive `[start, end]` index range, within `comments`, of the contiguous
+/// own-line comment block containing `comments[index]`.
+///
+/// The block grows outward from `index` while the neighbouring comment is on its own line, has
+/// the same [`CommentKind`], and isn't separated from its neighbour by a blank line or a line of
+/// code.
+/// Returns `None` if `comments[index]` isn't an own-line comment, since only own-line comments
+/// form blocks.
+fn comment_block_range(
+    comments: &[&SourceComment],
+    index: usize,
+    source_code: SourceCode,
+) -> Option<(usize, usize)> {
+    if !comments[index].position().is_own_line() {
+        return None;
+    }
+
+    let kind = comments[index].kind();
+
+    let mut start = index;
+    while start > 0
+        && comments[start - 1].position().is_own_line()
+        && comments[start - 1].kind() == kind
+        && !is_blank_line_separated(comments[start - 1], comments[start], source_code)
+    {
+        start -= 1;
+    }
+
+    let mut end = index;
+    while end + 1 < comments.len()
+        && comments[end + 1].position().is_own_line()
+        && comments[end + 1].kind() == kind
+        && !is_blank_line_separated(comments[end], comments[end + 1], source_code)
+    {
+        end += 1;
+    }
+
+    Some((start, end))
+}
+
+/// Returns `true` if at least one whole source line, blank or holding code, separates `before`
+/// from `after`.
+fn is_blank_line_separated(
+    before: &SourceComment,
+    after: &SourceComment,
+    source_code: SourceCode,
+) -> bool {
+    let between = source_code.slice(TextRange::new(before.slice().end(), after.slice().start()));
+
+    is_blank_gap(between)
+}
+
+/// Returns `true` if the source text `between` two comments contains more than one line break,
+/// i.e. a blank line or a line of code lies between them. Pulled out of [`is_blank_line_separated`] as a pure function of the gap text so
+/// the blank-line rule can be unit tested without constructing a [`SourceCode`].
+fn is_blank_gap(between: &str) -> bool {
+    between.matches('\n').count() > 1
+}
+
+/// A contiguous run of own-line comments of the same [`CommentKind`], with no blank line or
+/// non-comment token between them. See [`Comments::comment_block_containing`].
+///
+/// Stores its comments by reference rather than as a single slice because a block can span
+/// `Comments`'s separately stored leading, dangling, and trailing comment lists.
+#[derive(Debug)]
+pub(crate) struct CommentBlock<'a> {
+    comments: Vec<&'a SourceComment>,
+}
+
+impl<'a> CommentBlock<'a> {
+    /// Returns the comments that make up this block, in source order.
+    pub(crate) fn comments(&self) -> &[&'a SourceComment] {
+        &self.comments
+    }
+
+    /// Returns the first comment of the block.
+    pub(crate) fn first(&self) -> &'a SourceComment {
+        self.comments[0]
+    }
+
+    /// Returns the last comment of the block.
+    pub(crate) fn last(&self) -> &'a SourceComment {
+        self.comments[self.comments.len() - 1]
+    }
 }
 
 #[derive(Default)]
 struct CommentsData<'a> {
     comments: CommentsMap<'a>,
-}
\ No newline at end of file
+}
+
+/// A consuming cursor over a slice of [`SourceComment`]s.
+///
+/// [`peek`](CommentCursor::peek) inspects the next comment without consuming it, which lets a rule
+/// decide whether a comment belongs to it based on a predicate. [`next`](CommentCursor::next) (via
+/// the [`Iterator`] implementation) consumes the comment and, in debug builds, marks it as
+/// formatted, so that once a rule commits to taking a comment it can't forget to format it.
+#[derive(Debug, Clone)]
+pub(crate) struct CommentCursor<'a> {
+    iter: std::slice::Iter<'a, SourceComment>,
+}
+
+impl<'a> CommentCursor<'a> {
+    fn new(comments: &'a [SourceComment]) -> Self {
+        Self {
+            iter: comments.iter(),
+        }
+    }
+
+    /// Returns the next comment without consuming it.
+    pub(crate) fn peek(&self) -> Option<&'a SourceComment> {
+        self.iter.clone().next()
+    }
+
+    /// Returns `true` if the cursor has no more comments left.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.iter.as_slice().is_empty()
+    }
+}
+
+impl<'a> Iterator for CommentCursor<'a> {
+    type Item = &'a SourceComment;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let comment = self.iter.next()?;
+        comment.mark_formatted();
+        Some(comment)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_comment_text, CommentKind};
+
+    #[test]
+    fn classifies_shebang_only_on_first_line() {
+        assert_eq!(
+            CommentKind::from_comment_text("#!/usr/bin/env python", true),
+            CommentKind::Shebang
+        );
+        assert_eq!(
+            CommentKind::from_comment_text("#!/usr/bin/env python", false),
+            CommentKind::Regular
+        );
+    }
+
+    #[test]
+    fn classifies_fmt_directives_with_and_without_space() {
+        for text in [
+            "# fmt: off",
+            "# fmt: on",
+            "# fmt: skip",
+            "#fmt:off",
+            "#fmt:skip",
+        ] {
+            assert_eq!(
+                CommentKind::from_comment_text(text, false),
+                CommentKind::FormatterDirective,
+                "{text:?} should be a formatter directive"
+            );
+        }
+    }
+
+    #[test]
+    fn classifies_noqa_with_and_without_codes() {
+        assert_eq!(
+            CommentKind::from_comment_text("# noqa", false),
+            CommentKind::Noqa
+        );
+        assert_eq!(
+            CommentKind::from_comment_text("# NOQA", false),
+            CommentKind::Noqa
+        );
+        assert_eq!(
+            CommentKind::from_comment_text("# noqa: E501", false),
+            CommentKind::Noqa
+        );
+    }
+
+    #[test]
+    fn classifies_type_comments() {
+        assert_eq!(
+            CommentKind::from_comment_text("# type: int", false),
+            CommentKind::TypeComment
+        );
+    }
+
+    #[test]
+    fn double_hash_banner_is_not_a_directive() {
+        // A second `#` makes it a banner comment, not a directive, even if the text matches one.
+        assert_eq!(
+            CommentKind::from_comment_text("## fmt: off", false),
+            CommentKind::Regular
+        );
+        assert_eq!(
+            CommentKind::from_comment_text("## noqa", false),
+            CommentKind::Regular
+        );
+    }
+
+    #[test]
+    fn regular_comment_is_regular() {
+        assert_eq!(
+            CommentKind::from_comment_text("# just a comment", false),
+            CommentKind::Regular
+        );
+    }
+
+    #[test]
+    fn normalizes_single_space() {
+        assert_eq!(normalize_comment_text("# comment", false), "# comment");
+    }
+
+    #[test]
+    fn normalizes_multiple_leading_spaces() {
+        assert_eq!(normalize_comment_text("#  comment", false), "# comment");
+        assert_eq!(normalize_comment_text("#   x", false), "# x");
+    }
+
+    #[test]
+    fn normalizes_missing_space() {
+        assert_eq!(normalize_comment_text("#comment", false), "# comment");
+    }
+
+    #[test]
+    fn trims_trailing_whitespace() {
+        assert_eq!(normalize_comment_text("# comment   ", false), "# comment");
+    }
+
+    #[test]
+    fn preserves_multi_hash_banner_prefix() {
+        assert_eq!(normalize_comment_text("##  Heading", false), "## Heading");
+        assert_eq!(normalize_comment_text("### Big", false), "### Big");
+    }
+
+    #[test]
+    fn leaves_bare_hash_untouched() {
+        assert_eq!(normalize_comment_text("#", false), "#");
+        assert_eq!(normalize_comment_text("##", false), "##");
+    }
+
+    #[test]
+    fn leaves_cell_markers_untouched() {
+        assert_eq!(normalize_comment_text("#%%", false), "#%%");
+        assert_eq!(normalize_comment_text("#%%  cell", false), "#%%  cell");
+    }
+
+    #[test]
+    fn leaves_verbatim_comments_untouched() {
+        assert_eq!(normalize_comment_text("#type:  int", true), "#type:  int");
+    }
+
+    use super::{CommentCursor, SourceComment};
+    use ruff_formatter::{SourceCode, SourceCodeSlice};
+    use ruff_text_size::{TextRange, TextSize};
+
+    /// Builds a [`SourceComment`] for the `#`-comment found at `needle` in `source`.
+    ///
+    /// `needle` must be unique in `source` so that the comment's range is unambiguous.
+    fn comment_in<'a>(source_code: SourceCode<'a>, source: &str, needle: &str) -> SourceComment {
+        let start = source.find(needle).expect("needle not found in source");
+        let range = TextRange::new(
+            TextSize::try_from(start).unwrap(),
+            TextSize::try_from(start + needle.len()).unwrap(),
+        );
+
+        SourceComment::new(SourceCodeSlice::new(range), source_code)
+    }
+
+    #[test]
+    fn cursor_peek_does_not_consume_or_mark_formatted() {
+        let source = "# a\n# b\n";
+        let source_code = SourceCode::new(source);
+        let comments = vec![
+            comment_in(source_code, source, "# a"),
+            comment_in(source_code, source, "# b"),
+        ];
+
+        let cursor = CommentCursor::new(&comments);
+
+        assert_eq!(cursor.peek().unwrap().slice().text(source_code), "# a");
+        assert_eq!(cursor.peek().unwrap().slice().text(source_code), "# a");
+        assert!(!comments[0].formatted.get());
+    }
+
+    #[test]
+    fn cursor_next_consumes_and_marks_formatted() {
+        let source = "# a\n# b\n";
+        let source_code = SourceCode::new(source);
+        let comments = vec![
+            comment_in(source_code, source, "# a"),
+            comment_in(source_code, source, "# b"),
+        ];
+
+        let mut cursor = CommentCursor::new(&comments);
+
+        assert!(!comments[0].formatted.get());
+        let first = cursor.next().unwrap();
+        assert_eq!(first.slice().text(source_code), "# a");
+        assert!(comments[0].formatted.get());
+        assert!(!comments[1].formatted.get());
+
+        assert_eq!(cursor.peek().unwrap().slice().text(source_code), "# b");
+        assert!(!comments[1].formatted.get());
+
+        let second = cursor.next().unwrap();
+        assert_eq!(second.slice().text(source_code), "# b");
+        assert!(comments[1].formatted.get());
+
+        assert!(cursor.next().is_none());
+        assert!(cursor.is_empty());
+    }
+
+    use super::{comment_block_range, is_blank_gap, CommentTextPosition};
+
+    #[test]
+    fn is_blank_gap_requires_more_than_one_line_break() {
+        assert!(!is_blank_gap(""));
+        assert!(!is_blank_gap("\n"));
+        assert!(!is_blank_gap("\n    "));
+        assert!(is_blank_gap("\n\n"));
+        assert!(is_blank_gap("\n    \n    "));
+    }
+
+    #[test]
+    fn block_merges_adjacent_same_kind_own_line_comments() {
+        let source = "# a\n# b\n# c\n";
+        let source_code = SourceCode::new(source);
+        let comments = [
+            comment_in(source_code, source, "# a"),
+            comment_in(source_code, source, "# b"),
+            comment_in(source_code, source, "# c"),
+        ];
+        let refs: Vec<&SourceComment> = comments.iter().collect();
+
+        assert_eq!(
+            comment_block_range(&refs, 1, source_code),
+            Some((0, 2)),
+            "all three own-line comments of the same kind should form a single block"
+        );
+    }
+
+    #[test]
+    fn block_breaks_on_kind_change() {
+        let source = "# a\n# type: int\n# c\n";
+        let source_code = SourceCode::new(source);
+        let comments = [
+            comment_in(source_code, source, "# a"),
+            comment_in(source_code, source, "# type: int"),
+            comment_in(source_code, source, "# c"),
+        ];
+        let refs: Vec<&SourceComment> = comments.iter().collect();
+
+        assert_eq!(
+            comments[1].kind(),
+            CommentKind::TypeComment,
+            "sanity check: the middle comment should be classified as a type comment"
+        );
+        assert_eq!(
+            comment_block_range(&refs, 1, source_code),
+            Some((1, 1)),
+            "a kind change on either side should break the block"
+        );
+    }
+
+    #[test]
+    fn block_breaks_on_blank_line() {
+        let source = "# a\n\n# b\n";
+        let source_code = SourceCode::new(source);
+        let comments = [
+            comment_in(source_code, source, "# a"),
+            comment_in(source_code, source, "# b"),
+        ];
+        let refs: Vec<&SourceComment> = comments.iter().collect();
+
+        assert_eq!(
+            comment_block_range(&refs, 0, source_code),
+            Some((0, 0)),
+            "a blank line between the comments should break the block"
+        );
+        assert_eq!(comment_block_range(&refs, 1, source_code), Some((1, 1)));
+    }
+
+    #[test]
+    fn end_of_line_comment_does_not_join_a_block() {
+        let source = "a = 1 # trailing\n# own line\n";
+        let source_code = SourceCode::new(source);
+        let comments = [
+            comment_in(source_code, source, "# trailing"),
+            comment_in(source_code, source, "# own line"),
+        ];
+        let refs: Vec<&SourceComment> = comments.iter().collect();
+
+        assert_eq!(
+            comments[0].position(),
+            CommentTextPosition::EndOfLine,
+            "sanity check: the first comment should be classified as end-of-line"
+        );
+        assert_eq!(comment_block_range(&refs, 0, source_code), None);
+    }
+}